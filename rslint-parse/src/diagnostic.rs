@@ -0,0 +1,62 @@
+use crate::parser::error::ParseDiagnosticType;
+use crate::parser::suggestion::Suggestion;
+use crate::span::Span;
+use rslint_errors::Applicability;
+
+/// A diagnostic produced while parsing, built up through chained `.primary()`/`.secondary()`/
+/// `.help()`/`.suggestion()` calls the way the rest of the parser expects.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParserDiagnostic {
+    pub kind: ParseDiagnosticType,
+    pub message: String,
+    pub primary: Option<(Span, String)>,
+    pub secondary: Vec<(Span, String)>,
+    pub help: Vec<String>,
+    pub suggestions: Vec<Suggestion>,
+}
+
+impl ParserDiagnostic {
+    pub fn new(kind: ParseDiagnosticType, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            primary: None,
+            secondary: Vec::new(),
+            help: Vec::new(),
+            suggestions: Vec::new(),
+        }
+    }
+
+    pub fn primary(mut self, span: impl Into<Span>, message: impl Into<String>) -> Self {
+        self.primary = Some((span.into(), message.into()));
+        self
+    }
+
+    pub fn secondary(mut self, span: impl Into<Span>, message: impl Into<String>) -> Self {
+        self.secondary.push((span.into(), message.into()));
+        self
+    }
+
+    pub fn help(mut self, message: impl Into<String>) -> Self {
+        self.help.push(message.into());
+        self
+    }
+
+    /// Attaches a structured, machine-consumable fix to this diagnostic, following the same
+    /// span/replacement/[`Applicability`] shape rustc uses for its own suggestions.
+    pub fn suggestion(
+        mut self,
+        span: impl Into<Span>,
+        message: impl Into<String>,
+        replacement: impl Into<String>,
+        applicability: Applicability,
+    ) -> Self {
+        self.suggestions.push(Suggestion {
+            span: span.into(),
+            message: message.into(),
+            replacement: replacement.into(),
+            applicability,
+        });
+        self
+    }
+}
@@ -0,0 +1,211 @@
+pub mod token;
+
+use crate::diagnostic::ParserDiagnostic;
+use crate::parser::error::ParseDiagnosticType::UnexpectedToken;
+use crate::span::Span;
+use token::{BinToken, Token, TokenType};
+
+/// A hand-rolled lexer over a source string.
+///
+/// `pos` is the committed read position (the offset the next [`Lexer::advance`] will start
+/// from); `peek_pos` is a scratch cursor used by [`Lexer::peek`] for lookahead that hasn't been
+/// committed yet. [`Lexer::reset`] rewinds `peek_pos` back to `pos` without touching the
+/// committed position, and [`Lexer::position`]/[`Lexer::set_position`] expose `pos` directly so
+/// a [`crate::parser::checkpoint::ParserCheckpoint`] can snapshot/restore just an offset instead
+/// of cloning the whole lexer.
+#[derive(Debug, Clone)]
+pub struct Lexer<'a> {
+    source: &'a str,
+    pos: usize,
+    peek_pos: usize,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            pos: 0,
+            peek_pos: 0,
+        }
+    }
+
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    pub fn set_position(&mut self, pos: usize) {
+        self.pos = pos;
+        self.peek_pos = pos;
+    }
+
+    /// Rewinds the lookahead cursor used by [`Lexer::peek`] back to the committed position.
+    pub fn reset(&mut self) {
+        self.peek_pos = self.pos;
+    }
+
+    /// Lexes and commits the next token, advancing `pos` (and `peek_pos` along with it).
+    pub fn advance(&mut self) -> Result<Token, ParserDiagnostic> {
+        let (token, new_pos) = Self::lex_at(self.source, self.pos)?;
+        self.pos = new_pos;
+        self.peek_pos = new_pos;
+        Ok(token)
+    }
+
+    /// Lexes the next token after whatever has already been peeked, without committing it.
+    /// Returns `None` once the lookahead cursor reaches the end of the source.
+    pub fn peek(&mut self) -> Result<Option<Token>, ParserDiagnostic> {
+        if self.peek_pos >= self.source.len() {
+            return Ok(None);
+        }
+        let (token, new_pos) = Self::lex_at(self.source, self.peek_pos)?;
+        self.peek_pos = new_pos;
+        Ok(Some(token))
+    }
+
+    fn lex_at(source: &str, pos: usize) -> Result<(Token, usize), ParserDiagnostic> {
+        if pos >= source.len() {
+            return Ok((
+                Token {
+                    token_type: TokenType::EOF,
+                    lexeme: Span::new(pos, pos),
+                },
+                pos,
+            ));
+        }
+
+        let rest = &source[pos..];
+        let mut chars = rest.char_indices();
+        let (_, first) = chars.next().unwrap();
+
+        macro_rules! single {
+            ($kind:expr) => {
+                Ok((
+                    Token {
+                        token_type: $kind,
+                        lexeme: Span::new(pos, pos + 1),
+                    },
+                    pos + 1,
+                ))
+            };
+        }
+
+        match first {
+            ' ' | '\t' => {
+                let len = rest
+                    .char_indices()
+                    .take_while(|(_, c)| *c == ' ' || *c == '\t')
+                    .count();
+                Ok((
+                    Token {
+                        token_type: TokenType::Whitespace,
+                        lexeme: Span::new(pos, pos + len),
+                    },
+                    pos + len,
+                ))
+            }
+            '\n' | '\r' => {
+                let len = rest
+                    .char_indices()
+                    .take_while(|(_, c)| *c == '\n' || *c == '\r')
+                    .count();
+                Ok((
+                    Token {
+                        token_type: TokenType::Linebreak,
+                        lexeme: Span::new(pos, pos + len),
+                    },
+                    pos + len,
+                ))
+            }
+            '+' if rest.as_bytes().get(1) == Some(&b'+') => Ok((
+                Token {
+                    token_type: TokenType::Increment,
+                    lexeme: Span::new(pos, pos + 2),
+                },
+                pos + 2,
+            )),
+            '-' if rest.as_bytes().get(1) == Some(&b'-') => Ok((
+                Token {
+                    token_type: TokenType::Decrement,
+                    lexeme: Span::new(pos, pos + 2),
+                },
+                pos + 2,
+            )),
+            '+' => single!(TokenType::BinOp(BinToken::Add)),
+            '-' => single!(TokenType::BinOp(BinToken::Subtract)),
+            '~' => single!(TokenType::BitwiseNot),
+            '!' => single!(TokenType::LogicalNot),
+            '(' => single!(TokenType::ParenOpen),
+            ')' => single!(TokenType::ParenClose),
+            ';' => single!(TokenType::Semicolon),
+            '{' => single!(TokenType::BraceOpen),
+            '}' => single!(TokenType::BraceClose),
+            '/' => {
+                let mut len = 1;
+                let mut closed = false;
+                for (i, c) in rest[1..].char_indices() {
+                    len = i + 2;
+                    if c == '/' {
+                        closed = true;
+                        break;
+                    }
+                }
+                if !closed {
+                    return Err(ParserDiagnostic::new(
+                        UnexpectedToken,
+                        "Unterminated regular expression literal",
+                    )
+                    .primary(Span::new(pos, pos + len), "regex literal is never closed"));
+                }
+                let flags_len = rest[len..]
+                    .char_indices()
+                    .take_while(|(_, c)| c.is_ascii_alphabetic())
+                    .count();
+                len += flags_len;
+                Ok((
+                    Token {
+                        token_type: TokenType::Regex,
+                        lexeme: Span::new(pos, pos + len),
+                    },
+                    pos + len,
+                ))
+            }
+            c if c.is_ascii_digit() => {
+                let len = rest
+                    .char_indices()
+                    .take_while(|(_, c)| c.is_ascii_digit())
+                    .count();
+                Ok((
+                    Token {
+                        token_type: TokenType::Number,
+                        lexeme: Span::new(pos, pos + len),
+                    },
+                    pos + len,
+                ))
+            }
+            c if c.is_ascii_alphabetic() || c == '_' || c == '$' => {
+                let len = rest
+                    .char_indices()
+                    .take_while(|(_, c)| c.is_ascii_alphanumeric() || *c == '_' || *c == '$')
+                    .count();
+                let word = &rest[..len];
+                let token_type = match word {
+                    "delete" => TokenType::Delete,
+                    "void" => TokenType::Void,
+                    "typeof" => TokenType::Typeof,
+                    "true" => TokenType::True,
+                    "false" => TokenType::False,
+                    _ => TokenType::Identifier,
+                };
+                Ok((
+                    Token {
+                        token_type,
+                        lexeme: Span::new(pos, pos + len),
+                    },
+                    pos + len,
+                ))
+            }
+            _ => Err(ParserDiagnostic::new(UnexpectedToken, "Unexpected character")
+                .primary(Span::new(pos, pos + 1), "this character is not valid here")),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use crate::span::Span;
+
+/// The binary operators recognized at the lexer level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinToken {
+    Add,
+    Subtract,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenType {
+    Whitespace,
+    Linebreak,
+    Increment,
+    Decrement,
+    Delete,
+    Void,
+    Typeof,
+    True,
+    False,
+    Identifier,
+    Number,
+    Regex,
+    ParenOpen,
+    ParenClose,
+    Semicolon,
+    BraceOpen,
+    BraceClose,
+    BinOp(BinToken),
+    BitwiseNot,
+    LogicalNot,
+    EOF,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub token_type: TokenType,
+    pub lexeme: Span,
+}
@@ -0,0 +1,28 @@
+// `ParserDiagnostic` carries its labels/help/suggestions inline rather than behind a `Box`, so
+// it's larger than clippy's default `Result` size threshold; that's an intentional tradeoff
+// (diagnostics are the cold path) rather than an oversight.
+#![allow(clippy::result_large_err)]
+
+pub mod diagnostic;
+pub mod lexer;
+pub mod parser;
+pub mod span;
+
+/// Parses `$source` as a standalone unary expression, for use in tests.
+#[macro_export]
+macro_rules! expr {
+    ($source:expr) => {{
+        let mut parser = $crate::parser::Parser::with_source($source, 0, true).unwrap();
+        parser.parse_unary_expr(None).unwrap()
+    }};
+}
+
+/// Builds the [`Span`](crate::span::Span) covering the first occurrence of `$substr` within
+/// `$source`, for use in tests.
+#[macro_export]
+macro_rules! span {
+    ($source:expr, $substr:expr) => {{
+        let start = $source.find($substr).expect("substring not found in source");
+        $crate::span::Span::new(start, start + $substr.len())
+    }};
+}
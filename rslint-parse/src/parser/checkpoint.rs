@@ -0,0 +1,90 @@
+//! Snapshot/restore support for speculative parsing and error recovery.
+//!
+//! Mirrors rustc's `SnapshotParser`: [`Parser::checkpoint`] captures enough of the parser's
+//! state to later undo it with [`Parser::restore`] — just the current token, the lexer's
+//! committed offset, and how many errors have been recorded so far. It deliberately does not
+//! clone the lexer (or the source it scans): on a parser's happy path a checkpoint is taken
+//! before *every* prefix/postfix unary operand, so anything heavier than an offset would turn
+//! every such parse into an O(n) copy.
+//!
+//! This lets a subparser attempt a production, and on failure back out cleanly and take a
+//! recovery path instead of propagating the error all the way up and truncating the rest of
+//! the CST.
+
+use crate::diagnostic::ParserDiagnostic;
+use crate::lexer::token::{Token, TokenType};
+use crate::parser::Parser;
+
+/// A lightweight snapshot of [`Parser`] state taken by [`Parser::checkpoint`].
+#[derive(Debug, Clone)]
+pub struct ParserCheckpoint {
+    cur_tok: Token,
+    lexer_pos: usize,
+    errors_len: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Captures the parser's current position so a speculative parse can later be undone with
+    /// [`Parser::restore`].
+    pub fn checkpoint(&self) -> ParserCheckpoint {
+        ParserCheckpoint {
+            cur_tok: self.cur_tok.clone(),
+            lexer_pos: self.lexer.position(),
+            errors_len: self.errors.len(),
+        }
+    }
+
+    /// Rolls the parser back to a previously captured [`ParserCheckpoint`], discarding any
+    /// errors recorded since it was taken (the caller is expected to push its own single
+    /// diagnostic describing the recovery instead).
+    pub fn restore(&mut self, checkpoint: ParserCheckpoint) {
+        self.cur_tok = checkpoint.cur_tok;
+        self.lexer.set_position(checkpoint.lexer_pos);
+        self.errors.truncate(checkpoint.errors_len);
+    }
+
+    /// Skips tokens until a statement boundary (`;`, `{`, `}`, or EOF) is reached, so that a
+    /// single malformed expression doesn't take the rest of the statement/CST down with it.
+    pub(crate) fn recover_to_stmt_boundary(&mut self) -> Result<(), ParserDiagnostic> {
+        loop {
+            match self.cur_tok.token_type {
+                TokenType::Semicolon
+                | TokenType::BraceOpen
+                | TokenType::BraceClose
+                | TokenType::EOF => return Ok(()),
+                _ => {
+                    self.advance_lexer(false)?;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::Parser;
+
+    #[test]
+    fn checkpoint_restore_round_trip() {
+        let mut parser = Parser::with_source("foo bar", 0, false).unwrap();
+        let checkpoint = parser.checkpoint();
+
+        let first = parser.parse_unary_expr(None).unwrap();
+        assert_ne!(parser.cur_tok.lexeme.start, checkpoint.lexer_pos);
+
+        parser.restore(checkpoint);
+        let replayed = parser.parse_unary_expr(None).unwrap();
+        assert_eq!(first, replayed);
+    }
+
+    #[test]
+    fn checkpoint_discards_errors_recorded_since() {
+        let mut parser = Parser::with_source("true++", 0, false).unwrap();
+        let checkpoint = parser.checkpoint();
+        parser.parse_unary_expr(None).unwrap();
+        assert_eq!(parser.errors.len(), 1);
+
+        parser.restore(checkpoint);
+        assert_eq!(parser.errors.len(), 0);
+    }
+}
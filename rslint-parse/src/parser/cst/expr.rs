@@ -0,0 +1,85 @@
+use crate::lexer::token::TokenType;
+use crate::parser::Parser;
+use crate::span::Span;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LiteralWhitespace {
+    pub before: Span,
+    pub after: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LiteralExpr {
+    pub span: Span,
+    pub whitespace: LiteralWhitespace,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpdateExpr {
+    pub span: Span,
+    pub prefix: bool,
+    pub object: Box<Expr>,
+    pub op: TokenType,
+    pub whitespace: LiteralWhitespace,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnaryExpr {
+    pub span: Span,
+    pub object: Box<Expr>,
+    pub op: TokenType,
+    pub whitespace: LiteralWhitespace,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupingExpr {
+    pub span: Span,
+    pub expr: Box<Expr>,
+    pub opening_paren_whitespace: LiteralWhitespace,
+    pub closing_paren_whitespace: LiteralWhitespace,
+}
+
+/// A placeholder standing in for an operand that failed to parse, produced by the error
+/// recovery in `parse_unary_expr` so the surrounding expression still has something to hold
+/// onto instead of the whole parse aborting.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidExpr {
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Identifier(LiteralExpr),
+    Number(LiteralExpr),
+    True(LiteralExpr),
+    False(LiteralExpr),
+    Regex(LiteralExpr),
+    Update(UpdateExpr),
+    Unary(UnaryExpr),
+    Grouping(GroupingExpr),
+    Invalid(InvalidExpr),
+}
+
+impl Expr {
+    pub fn span(&self) -> &Span {
+        match self {
+            Expr::Identifier(lit)
+            | Expr::Number(lit)
+            | Expr::True(lit)
+            | Expr::False(lit)
+            | Expr::Regex(lit) => &lit.span,
+            Expr::Update(update) => &update.span,
+            Expr::Unary(unary) => &unary.span,
+            Expr::Grouping(grouping) => &grouping.span,
+            Expr::Invalid(invalid) => &invalid.span,
+        }
+    }
+
+    /// Whether this expression is valid on the left-hand side of an assignment or
+    /// `++`/`--`. `Invalid` placeholders count as valid targets here too: the operand they
+    /// stand in for already failed to parse and raised its own diagnostic, so flagging it
+    /// again would just be a duplicate diagnostic.
+    pub fn is_valid_assign_target(&self, _parser: &Parser) -> bool {
+        matches!(self, Expr::Identifier(_) | Expr::Invalid(_))
+    }
+}
@@ -0,0 +1,8 @@
+/// The kinds of diagnostics the parser can produce, independent of their message text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseDiagnosticType {
+    InvalidTargetExpression,
+    IdentifierDeletion,
+    ExprNestedTooDeeply,
+    UnexpectedToken,
+}
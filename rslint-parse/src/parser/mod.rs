@@ -0,0 +1,113 @@
+pub mod cst;
+pub mod error;
+mod checkpoint;
+mod recursion;
+mod subparsers;
+pub mod suggestion;
+
+use crate::diagnostic::ParserDiagnostic;
+use crate::lexer::token::Token;
+use crate::lexer::Lexer;
+use crate::parser::error::ParseDiagnosticType;
+use crate::span::Span;
+
+pub use checkpoint::ParserCheckpoint;
+
+/// Parser-wide mutable state that isn't part of the token stream itself.
+#[derive(Debug)]
+struct ParserState {
+    /// `Some` while parsing strict-mode code.
+    strict: Option<()>,
+    /// How many nested unary/update/grouping expressions are currently being parsed; see
+    /// `recursion.rs`.
+    expr_nesting: u32,
+}
+
+/// A hand-rolled recursive-descent parser over a source string.
+pub struct Parser<'a> {
+    source: &'a str,
+    file_id: usize,
+    lexer: Lexer<'a>,
+    cur_tok: Token,
+    errors: Vec<ParserDiagnostic>,
+    state: ParserState,
+}
+
+impl<'a> Parser<'a> {
+    pub fn with_source(
+        source: &'a str,
+        file_id: usize,
+        strict: bool,
+    ) -> Result<Self, ParserDiagnostic> {
+        let mut lexer = Lexer::new(source);
+        let cur_tok = lexer.advance()?;
+        Ok(Self {
+            source,
+            file_id,
+            lexer,
+            cur_tok,
+            errors: Vec::new(),
+            state: ParserState {
+                strict: if strict { Some(()) } else { None },
+                expr_nesting: 0,
+            },
+        })
+    }
+
+    pub fn source(&self) -> &'a str {
+        self.source
+    }
+
+    pub fn file_id(&self) -> usize {
+        self.file_id
+    }
+
+    pub fn errors(&self) -> &[ParserDiagnostic] {
+        &self.errors
+    }
+
+    /// Advances to the next raw token from the lexer.
+    pub fn advance_lexer(&mut self, _skip_whitespace: bool) -> Result<(), ParserDiagnostic> {
+        self.cur_tok = self.lexer.advance()?;
+        Ok(())
+    }
+
+    /// Consumes contiguous whitespace (and, if `consume_linebreak` is set, linebreak) tokens
+    /// starting at the current token, returning the span they cover. Returns a zero-width span
+    /// at the current token's start if there is nothing to consume.
+    pub fn whitespace(&mut self, consume_linebreak: bool) -> Result<Span, ParserDiagnostic> {
+        use crate::lexer::token::TokenType;
+
+        let start = self.cur_tok.lexeme.start;
+        let mut end = start;
+        loop {
+            match self.cur_tok.token_type {
+                TokenType::Whitespace => {
+                    end = self.cur_tok.lexeme.end;
+                    self.advance_lexer(false)?;
+                }
+                TokenType::Linebreak if consume_linebreak => {
+                    end = self.cur_tok.lexeme.end;
+                    self.advance_lexer(false)?;
+                }
+                _ => break,
+            }
+        }
+        Ok(self.span(start, end))
+    }
+
+    /// Peeks at the token after whatever has already been peeked since the last
+    /// [`Parser::advance_lexer`] call, without consuming it. Call `self.lexer.reset()` to
+    /// rewind the lookahead once done peeking.
+    pub fn peek_lexer(&mut self) -> Result<Option<Token>, ParserDiagnostic> {
+        self.lexer.peek()
+    }
+
+    pub fn error(&self, kind: ParseDiagnosticType, message: &str) -> ParserDiagnostic {
+        ParserDiagnostic::new(kind, message)
+    }
+
+    pub fn span(&self, start: usize, end: usize) -> Span {
+        Span::new(start, end)
+    }
+}
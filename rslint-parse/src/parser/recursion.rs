@@ -0,0 +1,52 @@
+//! Stack-overflow protection for the recursive-descent expression parser.
+//!
+//! `parse_unary_expr` and friends recurse directly into themselves for every prefix/postfix
+//! operator, so pathological input (`!!!!!!...x`, `- - - ...x`) can blow the native stack.
+//! rustc guards against the same problem in its expression parser with
+//! `ensure_sufficient_stack`, which grows the stack onto a heap-allocated segment once the
+//! remaining space drops below a threshold. We do the same thing here with the `stacker` crate.
+
+use crate::diagnostic::ParserDiagnostic;
+use crate::parser::cst::expr::Expr;
+use crate::parser::error::ParseDiagnosticType::ExprNestedTooDeeply;
+use crate::parser::Parser;
+use crate::span::Span;
+
+/// Red zone: grow the stack once fewer than this many bytes remain.
+const STACK_RED_ZONE: usize = 100 * 1024;
+
+/// Size of each heap-allocated stack segment grown by `stacker`.
+const STACK_GROWTH_SIZE: usize = 1024 * 1024;
+
+/// Maximum number of nested unary/update expressions allowed before the parser gives up with
+/// a diagnostic instead of continuing to recurse.
+pub const MAX_EXPR_NESTING: u32 = 1024;
+
+impl<'a> Parser<'a> {
+    /// Runs `f`, ensuring there is sufficient native stack space left, and bumping (then
+    /// restoring) the expression nesting counter so runaway recursion is caught with a clean
+    /// diagnostic rather than a process abort.
+    pub(crate) fn with_expr_recursion_guard<F>(
+        &mut self,
+        span: Span,
+        f: F,
+    ) -> Result<Expr, ParserDiagnostic>
+    where
+        F: FnOnce(&mut Self) -> Result<Expr, ParserDiagnostic>,
+    {
+        if self.state.expr_nesting >= MAX_EXPR_NESTING {
+            return Err(self.error(
+                ExprNestedTooDeeply,
+                &format!(
+                    "expression nested too deeply (limit is {})",
+                    MAX_EXPR_NESTING
+                ),
+            ).primary(span, "this expression is nested too deeply to parse"));
+        }
+
+        self.state.expr_nesting += 1;
+        let result = stacker::maybe_grow(STACK_RED_ZONE, STACK_GROWTH_SIZE, || f(self));
+        self.state.expr_nesting -= 1;
+        result
+    }
+}
@@ -0,0 +1,70 @@
+use crate::diagnostic::ParserDiagnostic;
+use crate::lexer::token::TokenType;
+use crate::parser::cst::expr::*;
+use crate::parser::error::ParseDiagnosticType::UnexpectedToken;
+use crate::parser::Parser;
+use crate::span::Span;
+
+impl<'a> Parser<'a> {
+    /// Parses a primary expression: identifiers, literals, and parenthesized (grouping)
+    /// expressions. `leading` is the whitespace already consumed before the current token by
+    /// the caller, if any.
+    pub fn parse_lhs_expr(&mut self, leading: Option<Span>) -> Result<Expr, ParserDiagnostic> {
+        let leading_whitespace = match leading {
+            Some(span) => span,
+            None => self.whitespace(true)?,
+        };
+
+        match self.cur_tok.token_type {
+            TokenType::ParenOpen => self.parse_grouping_expr(leading_whitespace),
+            TokenType::Identifier => Ok(Expr::Identifier(self.parse_literal(leading_whitespace)?)),
+            TokenType::Number => Ok(Expr::Number(self.parse_literal(leading_whitespace)?)),
+            TokenType::True => Ok(Expr::True(self.parse_literal(leading_whitespace)?)),
+            TokenType::False => Ok(Expr::False(self.parse_literal(leading_whitespace)?)),
+            TokenType::Regex => Ok(Expr::Regex(self.parse_literal(leading_whitespace)?)),
+            _ => Err(self
+                .error(UnexpectedToken, "Expected an expression")
+                .primary(self.cur_tok.lexeme.to_owned(), "Expected an expression here")),
+        }
+    }
+
+    fn parse_literal(&mut self, leading_whitespace: Span) -> Result<LiteralExpr, ParserDiagnostic> {
+        let span = self.cur_tok.lexeme.to_owned();
+        self.advance_lexer(false)?;
+        let after = self.whitespace(false)?;
+        Ok(LiteralExpr {
+            span,
+            whitespace: LiteralWhitespace {
+                before: leading_whitespace,
+                after,
+            },
+        })
+    }
+
+    fn parse_grouping_expr(&mut self, opening_before: Span) -> Result<Expr, ParserDiagnostic> {
+        let open_start = self.cur_tok.lexeme.start;
+        self.advance_lexer(false)?;
+        let opening_after = self.whitespace(false)?;
+
+        let guard_span = self.span(open_start, open_start);
+        let inner = self.with_expr_recursion_guard(guard_span, |p| p.parse_unary_expr(None))?;
+
+        let closing_before = self.whitespace(true)?;
+        let close_span = self.cur_tok.lexeme.to_owned();
+        self.advance_lexer(false)?;
+        let closing_after = self.whitespace(false)?;
+
+        Ok(Expr::Grouping(GroupingExpr {
+            span: self.span(open_start, close_span.end),
+            expr: Box::new(inner),
+            opening_paren_whitespace: LiteralWhitespace {
+                before: opening_before,
+                after: opening_after,
+            },
+            closing_paren_whitespace: LiteralWhitespace {
+                before: closing_before,
+                after: closing_after,
+            },
+        }))
+    }
+}
@@ -0,0 +1,2 @@
+mod lhs_expr;
+mod unary_expr;
@@ -2,6 +2,7 @@ use crate::diagnostic::ParserDiagnostic;
 use crate::lexer::token::{BinToken, TokenType};
 use crate::parser::cst::expr::*;
 use crate::parser::error::ParseDiagnosticType::*;
+use crate::parser::suggestion::Applicability;
 use crate::parser::Parser;
 use crate::span::Span;
 
@@ -10,10 +11,9 @@ impl<'a> Parser<'a> {
         &mut self,
         leading: Option<Span>,
     ) -> Result<Expr, ParserDiagnostic> {
-        let leading_whitespace = if leading.is_none() {
-            self.whitespace(true)?
-        } else {
-            leading.unwrap()
+        let leading_whitespace = match leading {
+            Some(span) => span,
+            None => self.whitespace(true)?,
         };
 
         match self.cur_tok.token_type {
@@ -22,7 +22,21 @@ impl<'a> Parser<'a> {
                 // Advance over the token
                 self.advance_lexer(false)?;
                 let after = self.whitespace(false)?;
-                let object = Box::new(self.parse_unary_expr(None)?);
+                let guard_span = self.span(start, start);
+                let checkpoint = self.checkpoint();
+                let object = Box::new(
+                    match self.with_expr_recursion_guard(guard_span, |p| p.parse_unary_expr(None)) {
+                        Ok(expr) => expr,
+                        Err(err) => {
+                            self.restore(checkpoint);
+                            self.errors.push(err);
+                            self.recover_to_stmt_boundary()?;
+                            Expr::Invalid(InvalidExpr {
+                                span: self.span(start, start),
+                            })
+                        }
+                    },
+                );
                 let end = object.span().end;
 
                 if !object.is_valid_assign_target(self) {
@@ -33,11 +47,17 @@ impl<'a> Parser<'a> {
                         )
                         .secondary(
                             start..start + 2,
-                            &format!("Prefix {:?} operation used here", t),
+                            format!("Prefix {:?} operation used here", t),
                         )
                         .primary(
                             object.span().to_owned(),
                             "Not a valid expression for the operator",
+                        )
+                        .suggestion(
+                            start..start + 2,
+                            format!("remove the {:?} operator", t),
+                            "",
+                            Applicability::MaybeIncorrect,
                         );
                     self.errors.push(err);
                 }
@@ -64,14 +84,33 @@ impl<'a> Parser<'a> {
                 let start = self.cur_tok.lexeme.start;
                 self.advance_lexer(false)?;
                 let after = self.whitespace(false)?;
-                let object = self.parse_unary_expr(None)?;
+                let guard_span = self.span(start, start);
+                let checkpoint = self.checkpoint();
+                let object =
+                    match self.with_expr_recursion_guard(guard_span, |p| p.parse_unary_expr(None)) {
+                        Ok(expr) => expr,
+                        Err(err) => {
+                            self.restore(checkpoint);
+                            self.errors.push(err);
+                            self.recover_to_stmt_boundary()?;
+                            Expr::Invalid(InvalidExpr {
+                                span: self.span(start, start),
+                            })
+                        }
+                    };
                 let end = object.span().end;
 
                 if self.state.strict.is_some() && t == TokenType::Delete {
                     if let Expr::Identifier(ref data) = object {
                         let err = self.error(IdentifierDeletion, "`delete` cannot be applied to identifiers in strict mode code")
                             .primary(data.span, "Attempting to delete this identifier is invalid")
-                            .help("Help: `delete` is used to delete object properties");
+                            .help("Help: `delete` is used to delete object properties")
+                            .suggestion(
+                                start..data.span.start,
+                                "remove the `delete` operator",
+                                "",
+                                Applicability::MachineApplicable,
+                            );
 
                         self.errors.push(err);
                     }
@@ -106,7 +145,7 @@ impl<'a> Parser<'a> {
                         had_linebreak = true;
                         continue;
                     }
-                    t @ _ => {
+                    t => {
                         next = t;
                         break;
                     }
@@ -137,10 +176,16 @@ impl<'a> Parser<'a> {
                     InvalidTargetExpression,
                     &format!("Invalid left hand side expression for postfix {:?}", op),
                 )
-                .secondary(op_span, &format!("Postfix {:?} used here", op))
+                .secondary(op_span.to_owned(), format!("Postfix {:?} used here", op))
                 .primary(
                     object.span().to_owned(),
                     "Not a valid expression for the operator",
+                )
+                .suggestion(
+                    op_span,
+                    format!("remove the {:?} operator", op),
+                    "",
+                    Applicability::MaybeIncorrect,
                 );
             self.errors.push(err);
         }
@@ -397,4 +442,57 @@ mod tests {
             })
         )
     }
+
+    #[test]
+    fn strict_mode_delete_identifier_suggests_removal() {
+        let mut parser = Parser::with_source("delete foo", 0, true).unwrap();
+        parser.parse_unary_expr(None).unwrap();
+        assert_eq!(parser.errors.len(), 1);
+        let err = &parser.errors[0];
+        assert_eq!(
+            err.kind,
+            crate::parser::error::ParseDiagnosticType::IdentifierDeletion
+        );
+        assert_eq!(err.suggestions.len(), 1);
+        let suggestion = &err.suggestions[0];
+        assert_eq!(suggestion.span, span!("delete foo", "delete "));
+        assert_eq!(suggestion.replacement, "");
+        assert_eq!(
+            suggestion.applicability,
+            crate::parser::suggestion::Applicability::MachineApplicable
+        );
+    }
+
+    #[test]
+    fn prefix_update_with_unparsable_operand_reports_only_the_expression_error() {
+        let mut parser = Parser::with_source("++;", 0, true).unwrap();
+        parser.parse_unary_expr(None).unwrap();
+        assert_eq!(parser.errors.len(), 1);
+        assert_eq!(
+            parser.errors[0].kind,
+            crate::parser::error::ParseDiagnosticType::UnexpectedToken
+        );
+    }
+
+    #[test]
+    fn prefix_decrement_with_unparsable_operand_reports_only_the_expression_error() {
+        let mut parser = Parser::with_source("--)", 0, true).unwrap();
+        parser.parse_unary_expr(None).unwrap();
+        assert_eq!(parser.errors.len(), 1);
+        assert_eq!(
+            parser.errors[0].kind,
+            crate::parser::error::ParseDiagnosticType::UnexpectedToken
+        );
+    }
+
+    #[test]
+    fn deeply_nested_unary_reports_recursion_limit_instead_of_overflowing_stack() {
+        let source = format!("{}x", "!".repeat(2000));
+        let mut parser = Parser::with_source(&source, 0, true).unwrap();
+        parser.parse_unary_expr(None).unwrap();
+        assert!(parser
+            .errors
+            .iter()
+            .any(|e| e.kind == crate::parser::error::ParseDiagnosticType::ExprNestedTooDeeply));
+    }
 }
@@ -0,0 +1,16 @@
+//! Structured suggestions attached to parser diagnostics.
+//!
+//! Parse errors already carry primary/secondary labels and help text; [`ParserDiagnostic`]'s
+//! `.suggestion()` builder method (see `diagnostic.rs`) adds a concrete, machine-applicable fix
+//! alongside them, following the same pattern rustc uses in its own `diagnostics.rs` (a labeled
+//! span, replacement text, and an applicability) so that downstream tooling (IDE quick-fixes,
+//! `--fix`) has something to apply instead of just text to show the user.
+//!
+//! The `Applicability`/`Suggestion` shape itself lives in `rslint_errors` so it isn't redefined
+//! per-crate; this module just fixes the span type to this crate's [`Span`](crate::span::Span).
+
+use crate::span::Span;
+
+pub use rslint_errors::Applicability;
+
+pub type Suggestion = rslint_errors::Suggestion<Span>;
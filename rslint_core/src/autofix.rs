@@ -0,0 +1,112 @@
+use crate::cst::TextRange;
+
+/// How much confidence a [`Suggestion`] should be applied with.
+///
+/// This mirrors rustc's `Applicability` used for structured suggestions: it lets a rule
+/// describe *how sure* it is that a fix is correct, so tools (the `--fix` CLI flag, IDE
+/// quick-fixes, ...) can decide whether to apply it automatically or just surface it.
+///
+/// Shared with `rslint-parse`'s own suggestions via `rslint_errors`, rather than redefined here.
+pub use rslint_errors::Applicability;
+
+/// A single structured fix suggesting that `span` be replaced with `replacement`.
+///
+/// Rules attach these to diagnostics through [`crate::rule::RuleCtx`], they do not apply them
+/// directly; applying suggestions to source text is the job of [`Fixer`].
+pub type Suggestion = rslint_errors::Suggestion<TextRange>;
+
+/// Applies a set of [`Suggestion`]s to a source string.
+///
+/// Only `MachineApplicable` suggestions are applied. Overlapping suggestions are not applied
+/// together; when two spans overlap the later one (by start offset) is dropped and left for a
+/// subsequent pass, since applying both would produce out-of-sync spans.
+#[derive(Debug, Default)]
+pub struct Fixer {
+    suggestions: Vec<Suggestion>,
+}
+
+impl Fixer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, suggestion: Suggestion) {
+        self.suggestions.push(suggestion);
+    }
+
+    pub fn extend(&mut self, suggestions: impl IntoIterator<Item = Suggestion>) {
+        self.suggestions.extend(suggestions);
+    }
+
+    /// Rewrites `source`, applying every non-overlapping `MachineApplicable` suggestion.
+    ///
+    /// Suggestions are applied back-to-front so earlier spans stay valid as later ones are
+    /// rewritten.
+    pub fn apply(&self, source: &str) -> String {
+        let mut applicable: Vec<&Suggestion> = self
+            .suggestions
+            .iter()
+            .filter(|s| s.applicability == Applicability::MachineApplicable)
+            .collect();
+
+        applicable.sort_by_key(|s| s.span.start());
+
+        let mut non_overlapping: Vec<&Suggestion> = Vec::with_capacity(applicable.len());
+        let mut last_end = None;
+        for suggestion in applicable {
+            if last_end.is_some_and(|end| suggestion.span.start() < end) {
+                continue;
+            }
+            last_end = Some(suggestion.span.end());
+            non_overlapping.push(suggestion);
+        }
+
+        let mut result = source.to_string();
+        for suggestion in non_overlapping.into_iter().rev() {
+            let start: usize = suggestion.span.start();
+            let end: usize = suggestion.span.end();
+            result.replace_range(start..end, &suggestion.replacement);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn suggestion(start: usize, end: usize, replacement: &str, applicability: Applicability) -> Suggestion {
+        Suggestion {
+            span: TextRange::new(start, end),
+            message: "test suggestion".into(),
+            replacement: replacement.into(),
+            applicability,
+        }
+    }
+
+    #[test]
+    fn applies_non_overlapping_suggestions_in_any_insertion_order() {
+        let mut fixer = Fixer::new();
+        fixer.add(suggestion(6, 7, "", Applicability::MachineApplicable));
+        fixer.add(suggestion(0, 1, "b", Applicability::MachineApplicable));
+
+        assert_eq!(fixer.apply("a = 1;;"), "b = 1;");
+    }
+
+    #[test]
+    fn drops_the_later_of_two_overlapping_suggestions() {
+        let mut fixer = Fixer::new();
+        fixer.add(suggestion(0, 5, "foo", Applicability::MachineApplicable));
+        fixer.add(suggestion(3, 8, "bar", Applicability::MachineApplicable));
+
+        assert_eq!(fixer.apply("hello world"), "foo world");
+    }
+
+    #[test]
+    fn ignores_suggestions_that_are_not_machine_applicable() {
+        let mut fixer = Fixer::new();
+        fixer.add(suggestion(0, 1, "", Applicability::MaybeIncorrect));
+
+        assert_eq!(fixer.apply(";"), ";");
+    }
+}
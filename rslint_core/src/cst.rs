@@ -0,0 +1,122 @@
+//! A minimal, hand-rolled concrete syntax tree.
+//!
+//! Real `rslint_core` builds its CST on top of `rowan`: a homogeneous tree of
+//! [`SyntaxNode`]s tagged with a [`SyntaxKind`], so a [`crate::rule::CstRule`] can walk the
+//! whole tree uniformly without matching on a typed AST per construct. We keep that same shape
+//! here (an `Rc` tree with parent pointers) without pulling in `rowan` itself, to keep this
+//! crate's surface area small.
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+/// A byte range into the source text a [`SyntaxNode`] spans.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TextRange {
+    start: usize,
+    end: usize,
+}
+
+impl TextRange {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    pub fn end(&self) -> usize {
+        self.end
+    }
+}
+
+/// The kind of construct a [`SyntaxNode`] represents.
+///
+/// Variants are `SCREAMING_SNAKE_CASE` to match the convention `rowan`-based syntax kinds use
+/// elsewhere in the project.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SyntaxKind {
+    SCRIPT,
+    EMPTY_STMT,
+    BLOCK_STMT,
+    EXPR_STMT,
+    IF_STMT,
+    WHILE_STMT,
+    DO_WHILE_STMT,
+    FOR_STMT,
+    FOR_IN_STMT,
+    FOR_OF_STMT,
+    LABELLED_STMT,
+    WITH_STMT,
+    CLASS_DECL,
+    METHOD_DEF,
+}
+
+#[derive(Debug)]
+struct SyntaxNodeData {
+    kind: SyntaxKind,
+    range: TextRange,
+    parent: RefCell<Weak<SyntaxNodeData>>,
+    children: RefCell<Vec<SyntaxNode>>,
+}
+
+/// A node in the tree. Cheap to clone: it's just an `Rc` handle.
+#[derive(Debug, Clone)]
+pub struct SyntaxNode(Rc<SyntaxNodeData>);
+
+impl SyntaxNode {
+    pub fn kind(&self) -> SyntaxKind {
+        self.0.kind
+    }
+
+    pub fn text_range(&self) -> TextRange {
+        self.0.range
+    }
+
+    pub fn parent(&self) -> Option<SyntaxNode> {
+        self.0.parent.borrow().upgrade().map(SyntaxNode)
+    }
+
+    pub fn children(&self) -> Vec<SyntaxNode> {
+        self.0.children.borrow().clone()
+    }
+}
+
+/// A node under construction by the parser, before parent pointers can be wired up.
+pub struct RawNode {
+    pub kind: SyntaxKind,
+    pub range: TextRange,
+    pub children: Vec<RawNode>,
+}
+
+impl RawNode {
+    pub fn new(kind: SyntaxKind, range: TextRange, children: Vec<RawNode>) -> Self {
+        Self {
+            kind,
+            range,
+            children,
+        }
+    }
+
+    /// Builds the `Rc` tree from this raw node, wiring up every child's parent pointer.
+    pub fn into_syntax_node(self) -> SyntaxNode {
+        Self::build(self, Weak::new())
+    }
+
+    fn build(raw: RawNode, parent: Weak<SyntaxNodeData>) -> SyntaxNode {
+        let data = Rc::new(SyntaxNodeData {
+            kind: raw.kind,
+            range: raw.range,
+            parent: RefCell::new(parent),
+            children: RefCell::new(Vec::new()),
+        });
+        let children = raw
+            .children
+            .into_iter()
+            .map(|child| Self::build(child, Rc::downgrade(&data)))
+            .collect();
+        *data.children.borrow_mut() = children;
+        SyntaxNode(data)
+    }
+}
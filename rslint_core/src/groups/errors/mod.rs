@@ -0,0 +1,3 @@
+mod no_extra_semi;
+
+pub use no_extra_semi::NoExtraSemi;
@@ -1,3 +1,4 @@
+use crate::autofix::Applicability;
 use crate::rule_prelude::*;
 use SyntaxKind::*;
 
@@ -45,11 +46,17 @@ impl CstRule for NoExtraSemi {
         if node.kind() == SyntaxKind::EMPTY_STMT
             && node
                 .parent()
-                .map_or(true, |parent| !ALLOWED.contains(&parent.kind()))
+                .is_none_or(|parent| !ALLOWED.contains(&parent.kind()))
         {
             let err = ctx
                 .err(self.name(), "Unnecessary semicolon")
-                .primary(node, "help: delete this semicolon");
+                .primary(node, "help: delete this semicolon")
+                .suggestion(
+                    node.text_range(),
+                    "delete this semicolon",
+                    "",
+                    Applicability::MachineApplicable,
+                );
 
             ctx.add_err(err);
         }
@@ -58,7 +65,7 @@ impl CstRule for NoExtraSemi {
 }
 
 rule_tests! {
-  NoExtraSemi::default(),
+  NoExtraSemi,
   err: {
     ";",
     "
@@ -0,0 +1,40 @@
+pub mod autofix;
+pub mod cst;
+pub mod groups;
+pub mod parse;
+pub mod rule;
+pub mod rule_prelude;
+
+use crate::autofix::Fixer;
+use crate::rule::{run_rule, CstRule, RuleCtx, RuleDiagnostic};
+
+/// Lints `source` with `rules`, then applies every `MachineApplicable` suggestion the rules
+/// produced. This is the `--fix` entry point: the one place that actually constructs and runs
+/// a [`Fixer`] instead of leaving it an orphaned type nothing calls.
+pub fn lint_file_with_fix(source: &str, rules: &[Box<dyn CstRule>]) -> (String, Vec<RuleDiagnostic>) {
+    let root = parse::parse(source);
+    let mut ctx = RuleCtx::new();
+    for rule in rules {
+        run_rule(rule.as_ref(), &root, &mut ctx);
+    }
+
+    let mut fixer = Fixer::new();
+    fixer.extend(ctx.suggestions().cloned());
+
+    (fixer.apply(source), ctx.diagnostics().to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::groups::errors::NoExtraSemi;
+
+    #[test]
+    fn lint_file_with_fix_removes_the_extra_semicolon() {
+        let rules: Vec<Box<dyn CstRule>> = vec![Box::new(NoExtraSemi)];
+        let (fixed, diagnostics) = lint_file_with_fix(";", &rules);
+
+        assert_eq!(fixed, "");
+        assert_eq!(diagnostics.len(), 1);
+    }
+}
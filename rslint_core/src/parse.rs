@@ -0,0 +1,482 @@
+//! A small statement-level parser, just capable enough to build the [`SyntaxNode`] trees
+//! [`CstRule`](crate::rule::CstRule)s run over in tests and through [`crate::lint_file_with_fix`].
+//!
+//! This deliberately does not attempt to parse expressions: anything that isn't a statement
+//! keyword this module recognizes (`if`, `while`, `do`, `for`, `class`, a block, or `;`) is
+//! swallowed whole as an opaque [`SyntaxKind::EXPR_STMT`]. Rules that need to inspect
+//! expressions in detail aren't supported yet; the ones that ship today (`no-extra-semi`) only
+//! care about statement shape.
+
+use crate::cst::{RawNode, SyntaxKind, SyntaxNode};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tok {
+    Ident,
+    LParen,
+    RParen,
+    LBrace,
+    RBrace,
+    Semi,
+    Other,
+    Eof,
+}
+
+#[derive(Clone, Copy)]
+struct Lexer<'a> {
+    source: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+struct Token {
+    kind: Tok,
+    start: usize,
+    end: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            bytes: source.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn text(&self, tok: &Token) -> &'a str {
+        &self.source[tok.start..tok.end]
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.pos < self.bytes.len() && self.bytes[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+    }
+
+    fn next_token(&mut self) -> Token {
+        self.skip_whitespace();
+        let start = self.pos;
+        if self.pos >= self.bytes.len() {
+            return Token {
+                kind: Tok::Eof,
+                start,
+                end: start,
+            };
+        }
+
+        let c = self.bytes[self.pos] as char;
+        let kind = match c {
+            '(' => {
+                self.pos += 1;
+                Tok::LParen
+            }
+            ')' => {
+                self.pos += 1;
+                Tok::RParen
+            }
+            '{' => {
+                self.pos += 1;
+                Tok::LBrace
+            }
+            '}' => {
+                self.pos += 1;
+                Tok::RBrace
+            }
+            ';' => {
+                self.pos += 1;
+                Tok::Semi
+            }
+            c if c.is_ascii_alphabetic() || c == '_' || c == '$' => {
+                while self.pos < self.bytes.len() {
+                    let c = self.bytes[self.pos] as char;
+                    if c.is_ascii_alphanumeric() || c == '_' || c == '$' {
+                        self.pos += 1;
+                    } else {
+                        break;
+                    }
+                }
+                Tok::Ident
+            }
+            _ => {
+                self.pos += 1;
+                Tok::Other
+            }
+        };
+
+        Token {
+            kind,
+            start,
+            end: self.pos,
+        }
+    }
+}
+
+struct Parser<'a> {
+    lexer: Lexer<'a>,
+    cur: Token,
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a str) -> Self {
+        let mut lexer = Lexer::new(source);
+        let cur = lexer.next_token();
+        Self { lexer, cur }
+    }
+
+    fn text(&self) -> &'a str {
+        self.lexer.text(&self.cur)
+    }
+
+    fn is_ident(&self, word: &str) -> bool {
+        self.cur.kind == Tok::Ident && self.text() == word
+    }
+
+    fn bump(&mut self) -> Token {
+        let next = self.lexer.next_token();
+        std::mem::replace(&mut self.cur, next)
+    }
+
+    /// True if the token after `self.cur` is `(`, without consuming anything.
+    fn peek_is_paren(&self) -> bool {
+        let mut lookahead = self.lexer;
+        lookahead.next_token().kind == Tok::LParen
+    }
+
+    /// Consumes a balanced `(...)` group, if the current token is `(`. Used where we don't
+    /// parse expressions (conditions, argument lists) but still need to skip past them.
+    fn skip_balanced_parens(&mut self) {
+        if self.cur.kind != Tok::LParen {
+            return;
+        }
+        self.bump();
+        let mut depth = 1;
+        while depth > 0 && self.cur.kind != Tok::Eof {
+            match self.cur.kind {
+                Tok::LParen => depth += 1,
+                Tok::RParen => depth -= 1,
+                _ => {}
+            }
+            self.bump();
+        }
+    }
+
+    fn parse_script(mut self) -> RawNode {
+        let mut statements = Vec::new();
+        while self.cur.kind != Tok::Eof {
+            if self.cur.kind == Tok::RBrace {
+                // No enclosing block to match a stray top-level `}` against; skip it so the
+                // loop still makes forward progress instead of spinning on the same token.
+                self.bump();
+                continue;
+            }
+            statements.push(self.parse_statement());
+        }
+        let end = self.cur.start;
+        RawNode::new(
+            SyntaxKind::SCRIPT,
+            crate::cst::TextRange::new(0, end),
+            statements,
+        )
+    }
+
+    fn parse_statement(&mut self) -> RawNode {
+        match self.cur.kind {
+            Tok::Semi => {
+                let start = self.cur.start;
+                let end = self.cur.end;
+                self.bump();
+                RawNode::new(
+                    SyntaxKind::EMPTY_STMT,
+                    crate::cst::TextRange::new(start, end),
+                    Vec::new(),
+                )
+            }
+            Tok::LBrace => self.parse_block(),
+            Tok::Ident if self.is_ident("if") => self.parse_if(),
+            Tok::Ident if self.is_ident("while") => self.parse_while(),
+            Tok::Ident if self.is_ident("do") => self.parse_do_while(),
+            Tok::Ident if self.is_ident("for") => self.parse_for(),
+            Tok::Ident if self.is_ident("class") => self.parse_class(),
+            _ => self.parse_expr_stmt(),
+        }
+    }
+
+    fn parse_block(&mut self) -> RawNode {
+        let start = self.cur.start;
+        self.bump(); // `{`
+        let mut statements = Vec::new();
+        while self.cur.kind != Tok::RBrace && self.cur.kind != Tok::Eof {
+            statements.push(self.parse_statement());
+        }
+        let end = self.cur.end;
+        if self.cur.kind == Tok::RBrace {
+            self.bump();
+        }
+        RawNode::new(
+            SyntaxKind::BLOCK_STMT,
+            crate::cst::TextRange::new(start, end),
+            statements,
+        )
+    }
+
+    fn parse_if(&mut self) -> RawNode {
+        let start = self.cur.start;
+        self.bump(); // `if`
+        self.skip_balanced_parens();
+        let body = self.parse_statement();
+        let end = body.range.end();
+        RawNode::new(
+            SyntaxKind::IF_STMT,
+            crate::cst::TextRange::new(start, end),
+            vec![body],
+        )
+    }
+
+    fn parse_while(&mut self) -> RawNode {
+        let start = self.cur.start;
+        self.bump(); // `while`
+        self.skip_balanced_parens();
+        let body = self.parse_statement();
+        let end = body.range.end();
+        RawNode::new(
+            SyntaxKind::WHILE_STMT,
+            crate::cst::TextRange::new(start, end),
+            vec![body],
+        )
+    }
+
+    fn parse_do_while(&mut self) -> RawNode {
+        let start = self.cur.start;
+        self.bump(); // `do`
+        let body = self.parse_statement();
+        if self.is_ident("while") {
+            self.bump();
+            self.skip_balanced_parens();
+        }
+        let mut end = body.range.end();
+        if self.cur.kind == Tok::Semi {
+            end = self.cur.end;
+            self.bump();
+        }
+        RawNode::new(
+            SyntaxKind::DO_WHILE_STMT,
+            crate::cst::TextRange::new(start, end),
+            vec![body],
+        )
+    }
+
+    fn parse_for(&mut self) -> RawNode {
+        let start = self.cur.start;
+        self.bump(); // `for`
+        self.skip_balanced_parens();
+        let body = self.parse_statement();
+        let end = body.range.end();
+        RawNode::new(
+            SyntaxKind::FOR_STMT,
+            crate::cst::TextRange::new(start, end),
+            vec![body],
+        )
+    }
+
+    fn parse_class(&mut self) -> RawNode {
+        let start = self.cur.start;
+        self.bump(); // `class`
+        if self.cur.kind == Tok::Ident {
+            self.bump(); // name
+        }
+        if self.is_ident("extends") {
+            self.bump();
+            if self.cur.kind == Tok::Ident {
+                self.bump(); // superclass
+            }
+        }
+
+        let mut members = Vec::new();
+        if self.cur.kind == Tok::LBrace {
+            self.bump(); // `{`
+            while self.cur.kind != Tok::RBrace && self.cur.kind != Tok::Eof {
+                members.push(self.parse_class_member());
+            }
+            if self.cur.kind == Tok::RBrace {
+                self.bump();
+            }
+        }
+        let end = self.cur.start;
+        RawNode::new(
+            SyntaxKind::CLASS_DECL,
+            crate::cst::TextRange::new(start, end),
+            members,
+        )
+    }
+
+    fn parse_class_member(&mut self) -> RawNode {
+        if self.cur.kind == Tok::Semi {
+            let start = self.cur.start;
+            let end = self.cur.end;
+            self.bump();
+            return RawNode::new(
+                SyntaxKind::EMPTY_STMT,
+                crate::cst::TextRange::new(start, end),
+                Vec::new(),
+            );
+        }
+
+        let start = self.cur.start;
+        // Modifier keywords (`static`, `get`, `set`, `async`) and a generator `*` can precede
+        // the method name; skip any of them as long as they aren't themselves the name (i.e.
+        // the token after them isn't `(`, which would mean the "modifier" we just saw was
+        // actually the method name).
+        while self.cur.kind == Tok::Ident
+            && matches!(self.text(), "static" | "get" | "set" | "async")
+            && !self.peek_is_paren()
+        {
+            self.bump();
+        }
+        if self.cur.kind == Tok::Other && self.text() == "*" {
+            self.bump(); // generator `*`
+        }
+        if self.cur.kind == Tok::Ident {
+            self.bump(); // method name
+        }
+        self.skip_balanced_parens();
+        let body = if self.cur.kind == Tok::LBrace {
+            vec![self.parse_block()]
+        } else {
+            Vec::new()
+        };
+        if self.cur.start == start {
+            // None of the above recognized anything (e.g. a stray `)` or a number) — bump the
+            // unrecognized token so the caller's member loop always makes forward progress.
+            self.bump();
+        }
+        let end = body
+            .last()
+            .map(|b| b.range.end())
+            .unwrap_or(self.cur.start);
+        RawNode::new(
+            SyntaxKind::METHOD_DEF,
+            crate::cst::TextRange::new(start, end),
+            body,
+        )
+    }
+
+    /// Swallows tokens up to (and including) the next top-level `;`, `}`, or EOF, since we
+    /// don't parse expressions. `{`/`}` pairs nested inside the expression (object literals,
+    /// destructuring, ...) are tracked the same way `(`/`)` are, so a brace that belongs to the
+    /// expression itself isn't mistaken for the end of an enclosing block.
+    fn parse_expr_stmt(&mut self) -> RawNode {
+        let start = self.cur.start;
+        let mut end = self.cur.start;
+        let mut depth = 0;
+        loop {
+            match self.cur.kind {
+                Tok::Eof => break,
+                Tok::LParen | Tok::LBrace => {
+                    depth += 1;
+                    end = self.cur.end;
+                    self.bump();
+                }
+                Tok::RParen if depth > 0 => {
+                    depth -= 1;
+                    end = self.cur.end;
+                    self.bump();
+                }
+                Tok::RBrace if depth > 0 => {
+                    depth -= 1;
+                    end = self.cur.end;
+                    self.bump();
+                }
+                Tok::Semi if depth == 0 => {
+                    end = self.cur.end;
+                    self.bump();
+                    break;
+                }
+                Tok::RBrace if depth == 0 => break,
+                _ => {
+                    end = self.cur.end;
+                    self.bump();
+                }
+            }
+        }
+        RawNode::new(
+            SyntaxKind::EXPR_STMT,
+            crate::cst::TextRange::new(start, end),
+            Vec::new(),
+        )
+    }
+}
+
+/// Parses `source` into a [`SyntaxNode`] tree rooted at a [`SyntaxKind::SCRIPT`] node.
+pub fn parse(source: &str) -> SyntaxNode {
+    Parser::new(source).parse_script().into_syntax_node()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cst::SyntaxKind;
+
+    fn count_kind(node: &SyntaxNode, kind: SyntaxKind) -> usize {
+        let mut count = if node.kind() == kind { 1 } else { 0 };
+        for child in node.children() {
+            count += count_kind(&child, kind);
+        }
+        count
+    }
+
+    #[test]
+    fn braces_nested_inside_an_expression_statement_do_not_hang_the_parser() {
+        let root = parse("let a = {};");
+        assert_eq!(root.children().len(), 1);
+        assert_eq!(root.children()[0].kind(), SyntaxKind::EXPR_STMT);
+    }
+
+    #[test]
+    fn a_stray_closing_brace_is_left_for_the_enclosing_block_to_handle() {
+        let root = parse("{ let a = {}; }");
+        assert_eq!(root.children().len(), 1);
+        assert_eq!(root.children()[0].kind(), SyntaxKind::BLOCK_STMT);
+    }
+
+    #[test]
+    fn class_method_modifiers_do_not_produce_a_spurious_extra_method() {
+        let root = parse("class Foo { static foo() {} }");
+        let class = &root.children()[0];
+        assert_eq!(class.kind(), SyntaxKind::CLASS_DECL);
+        assert_eq!(count_kind(class, SyntaxKind::METHOD_DEF), 1);
+    }
+
+    #[test]
+    fn class_method_named_after_a_modifier_keyword_is_still_a_single_method() {
+        let root = parse("class Foo { static() {} }");
+        let class = &root.children()[0];
+        assert_eq!(count_kind(class, SyntaxKind::METHOD_DEF), 1);
+    }
+
+    #[test]
+    fn a_lone_stray_closing_brace_at_the_top_level_does_not_hang_the_parser() {
+        let root = parse("}");
+        assert_eq!(root.children().len(), 0);
+    }
+
+    #[test]
+    fn an_expression_statement_followed_by_a_stray_brace_does_not_hang_the_parser() {
+        let root = parse("foo}");
+        assert_eq!(root.children().len(), 1);
+        assert_eq!(root.children()[0].kind(), SyntaxKind::EXPR_STMT);
+    }
+
+    #[test]
+    fn an_unrecognized_class_member_token_does_not_hang_the_parser() {
+        let root = parse("class A{1}");
+        let class = &root.children()[0];
+        assert_eq!(class.kind(), SyntaxKind::CLASS_DECL);
+    }
+
+    #[test]
+    fn a_stray_paren_as_a_class_member_does_not_hang_the_parser() {
+        let root = parse("class A{)}");
+        let class = &root.children()[0];
+        assert_eq!(class.kind(), SyntaxKind::CLASS_DECL);
+    }
+}
@@ -0,0 +1,193 @@
+//! The rule trait rules implement, and the context they use to report diagnostics.
+
+use crate::autofix::{Applicability, Suggestion};
+use crate::cst::{SyntaxNode, TextRange};
+
+/// Metadata every lint rule exposes, independent of what kind of node it inspects.
+///
+/// Implemented by [`declare_lint!`](crate::declare_lint).
+pub trait Rule {
+    /// The rule's lint code, e.g. `"no-extra-semi"`.
+    fn name(&self) -> &'static str;
+}
+
+/// A rule that inspects [`SyntaxNode`]s one at a time.
+///
+/// Rules are `#[typetag::serde]` trait objects so a lint configuration (which rules are
+/// enabled, with what settings) can be deserialized rather than hardcoded.
+#[typetag::serde(tag = "rule")]
+pub trait CstRule: Rule + std::fmt::Debug {
+    /// Inspects a single node, reporting any diagnostics onto `ctx`. Called once per node as
+    /// the tree is walked; a rule doesn't need to recurse into children itself.
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()>;
+}
+
+/// A diagnostic reported by a [`CstRule`], once built.
+#[derive(Debug, Clone)]
+pub struct RuleDiagnostic {
+    pub rule_name: &'static str,
+    pub message: String,
+    pub primary: Option<(TextRange, String)>,
+    pub suggestions: Vec<Suggestion>,
+}
+
+/// Builder for a [`RuleDiagnostic`], mirroring `rslint-parse`'s `ParserDiagnostic` builder.
+#[derive(Debug, Clone)]
+pub struct RuleDiagnosticBuilder {
+    rule_name: &'static str,
+    message: String,
+    primary: Option<(TextRange, String)>,
+    suggestions: Vec<Suggestion>,
+}
+
+impl RuleDiagnosticBuilder {
+    pub fn primary(mut self, node: &SyntaxNode, message: impl Into<String>) -> Self {
+        self.primary = Some((node.text_range(), message.into()));
+        self
+    }
+
+    pub fn suggestion(
+        mut self,
+        span: TextRange,
+        message: impl Into<String>,
+        replacement: impl Into<String>,
+        applicability: Applicability,
+    ) -> Self {
+        self.suggestions.push(Suggestion {
+            span,
+            message: message.into(),
+            replacement: replacement.into(),
+            applicability,
+        });
+        self
+    }
+
+    fn build(self) -> RuleDiagnostic {
+        RuleDiagnostic {
+            rule_name: self.rule_name,
+            message: self.message,
+            primary: self.primary,
+            suggestions: self.suggestions,
+        }
+    }
+}
+
+/// Collects the diagnostics (and, transitively, the [`Suggestion`]s) rules report while
+/// walking a file, so they can be handed to a [`crate::autofix::Fixer`] afterwards.
+#[derive(Debug, Default)]
+pub struct RuleCtx {
+    diagnostics: Vec<RuleDiagnostic>,
+}
+
+impl RuleCtx {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn err(&self, rule_name: &'static str, message: impl Into<String>) -> RuleDiagnosticBuilder {
+        RuleDiagnosticBuilder {
+            rule_name,
+            message: message.into(),
+            primary: None,
+            suggestions: Vec::new(),
+        }
+    }
+
+    pub fn add_err(&mut self, diagnostic: RuleDiagnosticBuilder) {
+        self.diagnostics.push(diagnostic.build());
+    }
+
+    pub fn diagnostics(&self) -> &[RuleDiagnostic] {
+        &self.diagnostics
+    }
+
+    /// Every suggestion attached to a diagnostic reported so far, in report order.
+    pub fn suggestions(&self) -> impl Iterator<Item = &Suggestion> {
+        self.diagnostics.iter().flat_map(|d| d.suggestions.iter())
+    }
+}
+
+/// Runs `rule` over `node` and every node beneath it.
+pub fn run_rule(rule: &dyn CstRule, node: &SyntaxNode, ctx: &mut RuleCtx) {
+    rule.check_node(node, ctx);
+    for child in node.children() {
+        run_rule(rule, &child, ctx);
+    }
+}
+
+/// Declares a lint rule: a unit struct implementing [`Rule`], ready to have [`CstRule`]
+/// implemented on it.
+///
+/// ```ignore
+/// declare_lint! {
+///     /// Doc comment shown to users.
+///     #[derive(Default)]
+///     NoExtraSemi,
+///     errors,
+///     "no-extra-semi"
+/// }
+/// ```
+#[macro_export]
+macro_rules! declare_lint {
+    (
+        $(#[$attr:meta])*
+        $name:ident,
+        $group:ident,
+        $code:literal
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+        pub struct $name;
+
+        impl $crate::rule::Rule for $name {
+            fn name(&self) -> &'static str {
+                $code
+            }
+        }
+    };
+}
+
+/// Generates `#[test]` functions asserting a rule does (`err`) or doesn't (`ok`) report any
+/// diagnostics for each given source snippet.
+#[macro_export]
+macro_rules! rule_tests {
+    ($rule:expr, err: { $($err_src:expr),* $(,)? }, ok: { $($ok_src:expr),* $(,)? }) => {
+        #[cfg(test)]
+        mod rule_tests {
+            use super::*;
+
+            #[test]
+            fn reports_errors() {
+                $({
+                    let rule = $rule;
+                    let root = $crate::parse::parse($err_src);
+                    let mut ctx = $crate::rule::RuleCtx::new();
+                    $crate::rule::run_rule(&rule, &root, &mut ctx);
+                    assert!(
+                        !ctx.diagnostics().is_empty(),
+                        "expected {} to report an error for: {:?}",
+                        rule.name(),
+                        $err_src
+                    );
+                })*
+            }
+
+            #[test]
+            fn allows_valid_code() {
+                $({
+                    let rule = $rule;
+                    let root = $crate::parse::parse($ok_src);
+                    let mut ctx = $crate::rule::RuleCtx::new();
+                    $crate::rule::run_rule(&rule, &root, &mut ctx);
+                    assert!(
+                        ctx.diagnostics().is_empty(),
+                        "expected {} to allow: {:?}, got {:?}",
+                        rule.name(),
+                        $ok_src,
+                        ctx.diagnostics()
+                    );
+                })*
+            }
+        }
+    };
+}
@@ -0,0 +1,5 @@
+//! Everything a rule module needs; `use crate::rule_prelude::*;` at the top of one and go.
+
+pub use crate::cst::{SyntaxKind, SyntaxNode, TextRange};
+pub use crate::rule::{run_rule, CstRule, Rule, RuleCtx, RuleDiagnostic};
+pub use crate::{declare_lint, rule_tests};
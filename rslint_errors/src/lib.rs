@@ -0,0 +1,30 @@
+//! Shared diagnostic-suggestion primitives used across the RSLint crates.
+//!
+//! Both the linter (`rslint_core`) and the recursive-descent parser (`rslint-parse`) attach
+//! structured, rustc-style suggestions to their diagnostics, but they use different span
+//! representations. [`Suggestion`] is generic over the span type so each crate can plug in its
+//! own without duplicating the [`Applicability`] levels or the suggestion shape.
+
+/// How confident a [`Suggestion`] is that applying it is safe and correct.
+///
+/// Mirrors rustc's `Applicability` used for structured suggestions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Applicability {
+    /// The fix is definitely what the user wants and can be applied automatically.
+    MachineApplicable,
+    /// The fix may be what the user wants, but it is not certain.
+    MaybeIncorrect,
+    /// The fix contains placeholder text and must not be applied automatically.
+    HasPlaceholders,
+    /// The applicability of the fix is unknown.
+    Unspecified,
+}
+
+/// A single structured fix suggesting that `span` be replaced with `replacement`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion<S> {
+    pub span: S,
+    pub message: String,
+    pub replacement: String,
+    pub applicability: Applicability,
+}